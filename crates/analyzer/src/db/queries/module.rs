@@ -50,9 +50,8 @@ pub fn module_is_incomplete(db: &dyn AnalyzerDb, module: ModuleId) -> bool {
     if matches!(module.data(db).source, ModuleSource::File(_)) {
         let ast = module.ast(db);
         ast.body
-            .last()
-            .map(|stmt| matches!(stmt, ast::ModuleStmt::ParseError(_)))
-            .unwrap_or(false)
+            .iter()
+            .any(|stmt| matches!(stmt, ast::ModuleStmt::ParseError(_)))
     } else {
         false
     }
@@ -5,6 +5,7 @@ pub use codespan_reporting::diagnostic as cs;
 use codespan_reporting::files::Error as CsError;
 use codespan_reporting::term;
 pub use cs::Severity;
+use std::cmp::Ordering;
 use std::ops::Range;
 use std::rc::Rc;
 use term::termcolor::{BufferWriter, ColorChoice};
@@ -34,9 +35,58 @@ impl Diagnostic {
             notes: vec![],
         }
     }
+
+    /// The span of the first primary label, if there is one.
+    pub fn primary_span(&self) -> Option<Span> {
+        self.labels
+            .iter()
+            .find(|label| label.style == LabelStyle::Primary)
+            .map(|label| label.span)
+    }
+
+    /// The key used to put diagnostics in source order: the file and start
+    /// offset of the primary label. Diagnostics without a primary label sort
+    /// last.
+    fn position_key(&self) -> (bool, Option<(SourceFileId, usize)>) {
+        let position = self.primary_span().map(|span| (span.file_id, span.start));
+        (position.is_none(), position)
+    }
+}
+
+/// Diagnostics are ordered by the position of their primary label, so that
+/// they're reported top-to-bottom. Ties are broken by the rest of the
+/// diagnostic, to stay consistent with `Eq`.
+impl Ord for Diagnostic {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.position_key()
+            .cmp(&other.position_key())
+            .then_with(|| {
+                (self.severity, &self.message, &self.labels, &self.notes).cmp(&(
+                    other.severity,
+                    &other.message,
+                    &other.labels,
+                    &other.notes,
+                ))
+            })
+    }
+}
+
+impl PartialOrd for Diagnostic {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+/// Sort the given diagnostics into source order, by the start of their
+/// primary label. Diagnostics in the same file are kept together, and
+/// diagnostics without a primary label are moved to the end. Unlike sorting by
+/// `Ord`, the sort is stable, so diagnostics reported at the same position
+/// keep the order in which they were emitted.
+pub fn sort_by_position(diagnostics: &mut [Diagnostic]) {
+    diagnostics.sort_by_key(Diagnostic::position_key);
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub enum LabelStyle {
     Primary,
     Secondary,
@@ -50,7 +100,7 @@ impl From<LabelStyle> for cs::LabelStyle {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct Label {
     pub style: LabelStyle,
     pub span: Span,
@@ -143,3 +193,46 @@ impl<'a> codespan_reporting::files::Files<'_> for SourceDbWrapper<'a> {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diag_at(message: &str, start: usize) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            message: message.into(),
+            labels: vec![Label::primary(
+                Span::new(SourceFileId::dummy_file(), start, start + 1),
+                "",
+            )],
+            notes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_sort_by_position() {
+        let mut diags = vec![
+            Diagnostic::error("no labels".into()),
+            diag_at("c", 30),
+            diag_at("a", 2),
+            diag_at("b1", 10),
+            diag_at("b2", 10),
+        ];
+        sort_by_position(&mut diags);
+
+        let messages = diags
+            .iter()
+            .map(|diag| diag.message.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["a", "b1", "b2", "c", "no labels"]);
+    }
+
+    #[test]
+    fn test_diagnostic_ord() {
+        assert!(diag_at("z", 2) < diag_at("a", 10));
+        assert!(diag_at("a", 10) < diag_at("b", 10));
+        assert!(diag_at("z", 30) < Diagnostic::error("a".into()));
+        assert_eq!(diag_at("a", 2).cmp(&diag_at("a", 2)), Ordering::Equal);
+    }
+}
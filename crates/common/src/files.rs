@@ -57,7 +57,7 @@ macro_rules! impl_intern_key {
 // } from rust-analyzer
 
 // TODO: rename to FileId
-#[derive(Debug, serde::Deserialize, PartialEq, Eq, Hash, Copy, Clone)]
+#[derive(Debug, serde::Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub struct SourceFileId(pub(crate) u32);
 impl_intern_key!(SourceFileId);
 
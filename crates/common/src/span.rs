@@ -5,9 +5,9 @@ use std::fmt::{Debug, Formatter};
 use std::ops::{Add, AddAssign, Range};
 
 /// An exclusive span of byte offsets in a source file.
-#[derive(Serialize, Deserialize, PartialEq, Copy, Clone, Hash, Eq)]
+#[derive(Serialize, Deserialize, PartialEq, Copy, Clone, Hash, Eq, PartialOrd, Ord)]
 pub struct Span {
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default = "SourceFileId::dummy_file")]
     pub file_id: SourceFileId,
     /// A byte offset specifying the inclusive start of a span.
     pub start: usize,
@@ -64,6 +64,16 @@ impl Span {
             end: end_span.end,
         }
     }
+
+    /// The smallest span covering all of the given spans, or `None` if there
+    /// are none. Panics if the spans are not all in the same file.
+    pub fn enclosing<I, S>(spans: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<Span>,
+    {
+        spans.into_iter().map(Into::into).reduce(|a, b| a + b)
+    }
 }
 
 impl Debug for Span {
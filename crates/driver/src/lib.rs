@@ -7,7 +7,7 @@ use fe_analyzer::namespace::items::{IngotId, IngotMode, ModuleId};
 use fe_analyzer::AnalyzerDb;
 use fe_analyzer::{context::Analysis, namespace::items::ContractId};
 use fe_common::db::Upcast;
-use fe_common::diagnostics::{print_diagnostics, Diagnostic};
+use fe_common::diagnostics::{print_diagnostics, sort_by_position, Diagnostic};
 use fe_common::files::{FileKind, SourceFileId};
 use fe_mir::db::MirDb;
 use fe_parser::ast::SmolStr;
@@ -37,7 +37,9 @@ pub struct CompileError(pub Vec<Diagnostic>);
 
 pub fn check_single_file(db: &mut Db, path: &str, src: &str) -> Vec<Diagnostic> {
     let module = ModuleId::new_standalone(db, path, src);
-    module.diagnostics(db)
+    let mut diags = module.diagnostics(db);
+    sort_by_position(&mut diags);
+    diags
 }
 
 pub fn compile_single_file(
@@ -48,11 +50,12 @@ pub fn compile_single_file(
     optimize: bool,
 ) -> Result<CompiledModule, CompileError> {
     let module = ModuleId::new_standalone(db, path, src);
-    let diags = module.diagnostics(db);
+    let mut diags = module.diagnostics(db);
 
     if diags.is_empty() {
         compile_module_id(db, module, with_bytecode, optimize)
     } else {
+        sort_by_position(&mut diags);
         Err(CompileError(diags))
     }
 }
@@ -76,6 +79,7 @@ pub fn check_ingot(
 
     let mut diags = ingot.diagnostics(db);
     ingot.sink_external_ingot_diagnostics(db, &mut diags);
+    sort_by_position(&mut diags);
     diags
 }
 
@@ -103,6 +107,7 @@ pub fn compile_ingot(
     let mut diags = ingot.diagnostics(db);
     ingot.sink_external_ingot_diagnostics(db, &mut diags);
     if !diags.is_empty() {
+        sort_by_position(&mut diags);
         return Err(CompileError(diags));
     }
     let main_module = ingot
@@ -116,8 +121,9 @@ pub fn compile_ingot(
 pub fn dump_mir_single_file(db: &mut Db, path: &str, src: &str) -> Result<String, CompileError> {
     let module = ModuleId::new_standalone(db, path, src);
 
-    let diags = module.diagnostics(db);
+    let mut diags = module.diagnostics(db);
     if !diags.is_empty() {
+        sort_by_position(&mut diags);
         return Err(CompileError(diags));
     }
 
@@ -497,6 +497,32 @@ pub enum CompOperator {
     GtE,
 }
 
+impl Module {
+    /// Returns the contracts defined in this module, in source order.
+    pub fn contracts(&self) -> impl Iterator<Item = &Node<Contract>> {
+        self.body.iter().filter_map(|stmt| match stmt {
+            ModuleStmt::Contract(contract) => Some(contract),
+            _ => None,
+        })
+    }
+
+    /// Returns the structs defined in this module, in source order.
+    pub fn structs(&self) -> impl Iterator<Item = &Node<Struct>> {
+        self.body.iter().filter_map(|stmt| match stmt {
+            ModuleStmt::Struct(struct_) => Some(struct_),
+            _ => None,
+        })
+    }
+
+    /// Returns the free functions defined in this module, in source order.
+    pub fn functions(&self) -> impl Iterator<Item = &Node<Function>> {
+        self.body.iter().filter_map(|stmt| match stmt {
+            ModuleStmt::Function(function) => Some(function),
+            _ => None,
+        })
+    }
+}
+
 impl Node<Contract> {
     pub fn name(&self) -> &str {
         &self.kind.name.kind
@@ -1,5 +1,5 @@
 use super::functions::parse_fn_def;
-use super::types::{parse_field, parse_opt_qualifier};
+use super::types::{parse_field, parse_opt_qualifier, parse_pub_qualifier};
 
 use crate::ast::{Contract, ContractStmt};
 use crate::node::{Node, Span};
@@ -33,10 +33,10 @@ pub fn parse_contract_def(
 
     loop {
         par.eat_newlines();
-        let mut pub_qual = parse_opt_qualifier(par, TokenKind::Pub);
+        let mut pub_qual = parse_pub_qualifier(par)?;
         let const_qual = parse_opt_qualifier(par, TokenKind::Const);
         if pub_qual.is_none() && const_qual.is_some() && par.peek() == Some(TokenKind::Pub) {
-            pub_qual = parse_opt_qualifier(par, TokenKind::Pub);
+            pub_qual = parse_pub_qualifier(par)?;
             par.error(
                 pub_qual.unwrap() + const_qual,
                 "`const pub` should be written `pub const`",
@@ -18,6 +18,10 @@ pub fn parse_expr(par: &mut Parser) -> ParseResult<Node<Expr>> {
 /// Parse an expression, stopping if/when we reach an operator that binds less
 /// tightly than given binding power.
 pub fn parse_expr_with_min_bp(par: &mut Parser, min_bp: u8) -> ParseResult<Node<Expr>> {
+    par.nested(|par| parse_expr_with_min_bp_inner(par, min_bp))
+}
+
+fn parse_expr_with_min_bp_inner(par: &mut Parser, min_bp: u8) -> ParseResult<Node<Expr>> {
     let mut expr_head = parse_expr_head(par)?;
 
     while let Some(op) = par.peek() {
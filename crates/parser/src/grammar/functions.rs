@@ -319,7 +319,7 @@ fn aug_assign_op(tk: TokenKind) -> Option<BinOperator> {
     Some(op)
 }
 
-/// Parse a `continue`, `break`, `pass`, or `revert` statement.
+/// Parse a `continue` or `break` statement.
 ///
 /// # Panics
 /// Panics if the next token isn't one of the above.
@@ -336,6 +336,10 @@ pub fn parse_single_word_stmt(par: &mut Parser) -> ParseResult<Node<FuncStmt>> {
 
 /// Parse a function-level statement.
 pub fn parse_stmt(par: &mut Parser) -> ParseResult<Node<FuncStmt>> {
+    par.nested(parse_stmt_inner)
+}
+
+fn parse_stmt_inner(par: &mut Parser) -> ParseResult<Node<FuncStmt>> {
     use TokenKind::*;
 
     // rule: stmt parsing fns eat the trailing separator (newline, semi)
@@ -538,7 +542,7 @@ pub fn parse_if_stmt(par: &mut Parser) -> ParseResult<Node<FuncStmt>> {
         Some(TokenKind::Else) => {
             let else_tok = par.next()?;
             if par.peek() == Some(TokenKind::If) {
-                vec![parse_if_stmt(par)?]
+                vec![par.nested(parse_if_stmt)?]
             } else {
                 par.enter_block(else_tok.span, "`if` statement `else` branch")?;
                 let else_body = parse_block_stmts(par)?;
@@ -675,6 +679,20 @@ pub fn parse_return_stmt(par: &mut Parser) -> ParseResult<Node<FuncStmt>> {
 /// Panics if the next token isn't `assert`.
 pub fn parse_assert_stmt(par: &mut Parser) -> ParseResult<Node<FuncStmt>> {
     let assert_tok = par.assert(TokenKind::Assert);
+    if matches!(
+        par.peek(),
+        None | Some(TokenKind::Newline | TokenKind::Semi | TokenKind::BraceClose)
+    ) {
+        par.fancy_error(
+            "failed to parse `assert` statement",
+            vec![Label::primary(
+                assert_tok.span,
+                "expected a condition after `assert`",
+            )],
+            vec![r#"Example: `assert x > 0, "x must be positive"`"#.into()],
+        );
+        return Err(ParseFailed);
+    }
     let test = parse_expr(par)?;
     let msg = match par.peek() {
         None | Some(TokenKind::Newline) => None,
@@ -780,10 +798,10 @@ fn parse_tuple_pattern(par: &mut Parser, path: Option<Node<Path>>) -> ParseResul
             (vec![], par.next().unwrap().span)
         } else {
             par.eat_newlines();
-            let mut elts = vec![parse_pattern(par)?];
+            let mut elts = vec![par.nested(parse_pattern)?];
             while let Some(TokenKind::Comma) = par.peek() {
                 par.next().unwrap();
-                elts.push(parse_pattern(par)?);
+                elts.push(par.nested(parse_pattern)?);
                 par.eat_newlines();
             }
             let last_span = par.expect(TokenKind::ParenClose, "pattern")?.span;
@@ -816,7 +834,7 @@ fn parse_struct_pattern(par: &mut Parser, path: Node<Path>) -> ParseResult<Node<
                     let name = par.next().unwrap();
                     let field_name = Node::new(name.text.into(), name.span);
                     par.expect(TokenKind::Colon, "failed to parse struct pattern")?;
-                    let pat = parse_pattern(par)?;
+                    let pat = par.nested(parse_pattern)?;
                     fields.push((field_name, pat));
                     if par.peek() == Some(TokenKind::Comma) {
                         par.next()?;
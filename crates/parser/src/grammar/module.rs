@@ -1,8 +1,8 @@
 use super::expressions::parse_expr;
 use super::functions::parse_fn_def;
 use super::types::{
-    parse_impl_def, parse_path_tail, parse_struct_def, parse_trait_def, parse_type_alias,
-    parse_type_desc,
+    check_duplicate_pub, parse_impl_def, parse_path_tail, parse_struct_def, parse_trait_def,
+    parse_type_alias, parse_type_desc,
 };
 use super::{contracts::parse_contract_def, types::parse_enum_def};
 use crate::ast::{ConstantDecl, Module, ModuleStmt, Pragma, Use, UseTree};
@@ -21,12 +21,15 @@ pub fn parse_module(par: &mut Parser) -> Node<Module> {
             }
             None => break,
             Some(_) => {
+                let start = par.peek_span().unwrap();
                 match parse_module_stmt(par) {
                     Ok(stmt) => body.push(stmt),
                     Err(_) => {
-                        // TODO: capture a real span here
-                        body.push(ModuleStmt::ParseError(Span::zero(par.file_id)));
-                        break;
+                        // Skip the rest of the broken item, and try to parse
+                        // the next one, so that we can report as many errors
+                        // as possible.
+                        let skipped = par.skip_to_next_item(is_module_item_start);
+                        body.push(ModuleStmt::ParseError(start + skipped));
                     }
                 };
             }
@@ -50,6 +53,7 @@ pub fn parse_module_stmt(par: &mut Parser) -> ParseResult<ModuleStmt> {
         TokenKind::Const => ModuleStmt::Constant(parse_constant(par, None)?),
         TokenKind::Pub => {
             let pub_span = par.next()?.span;
+            check_duplicate_pub(par, pub_span)?;
             match par.peek_or_err()? {
                 TokenKind::Fn | TokenKind::Unsafe => {
                     ModuleStmt::Function(parse_fn_def(par, Some(pub_span))?)
@@ -79,7 +83,7 @@ pub fn parse_module_stmt(par: &mut Parser) -> ParseResult<ModuleStmt> {
             par.unexpected_token_error(
                 &tok,
                 "failed to parse module",
-                vec!["Note: expected import, contract, struct, type or const".into()],
+                vec!["Note: expected a top-level item: `pragma`, `use`, `contract`, `struct`, `enum`, `trait`, `impl`, `type`, `const` or `fn`".into()],
             );
             return Err(ParseFailed);
         }
@@ -87,6 +91,15 @@ pub fn parse_module_stmt(par: &mut Parser) -> ParseResult<ModuleStmt> {
     Ok(stmt)
 }
 
+/// Returns `true` if a module-level item can start with the given token.
+fn is_module_item_start(tk: TokenKind) -> bool {
+    use TokenKind::*;
+    matches!(
+        tk,
+        Pragma | Use | Contract | Struct | Enum | Trait | Impl | Type | Const | Pub | Fn | Unsafe
+    )
+}
+
 /// Parse a constant, e.g. `const MAGIC_NUMBER: u256 = 4711`.
 /// # Panics
 /// Panics if the next token isn't `const`.
@@ -242,9 +255,9 @@ pub fn parse_pragma(par: &mut Parser) -> ParseResult<Node<Pragma>> {
         }
     }
 
-    let version_requirement_span = match (tokens.first(), tokens.last()) {
-        (Some(first), Some(last)) => first.span + last.span,
-        _ => {
+    let version_requirement_span = match Span::enclosing(tokens.iter().map(|tok| tok.span)) {
+        Some(span) => span,
+        None => {
             par.error(
                 tok.span,
                 "failed to parse pragma statement: missing version requirement",
@@ -42,7 +42,7 @@ pub fn parse_struct_def(
 
         par.eat_newlines();
 
-        let pub_qual = par.optional(TokenKind::Pub).map(|tok| tok.span);
+        let pub_qual = parse_pub_qualifier(par)?;
         match par.peek_or_err()? {
             TokenKind::Name => {
                 let field = parse_field(par, attributes, pub_qual, None)?;
@@ -114,7 +114,9 @@ pub fn parse_enum_def(par: &mut Parser, pub_qual: Option<Span>) -> ParseResult<N
             }
 
             TokenKind::Pub => {
-                let pub_qual = Some(par.next().unwrap().span);
+                let pub_span = par.next()?.span;
+                check_duplicate_pub(par, pub_span)?;
+                let pub_qual = Some(pub_span);
                 match par.peek() {
                     Some(TokenKind::Fn | TokenKind::Unsafe) => {
                         functions.push(parse_fn_def(par, pub_qual)?);
@@ -242,6 +244,12 @@ pub fn parse_impl_def(par: &mut Parser) -> ParseResult<Node<Impl>> {
                 par.next()?;
                 break;
             }
+            TokenKind::Pub => {
+                let tok = par.next()?;
+                check_duplicate_pub(par, tok.span)?;
+                par.unexpected_token_error(&tok, "failed to parse `impl` definition body", vec![]);
+                return Err(ParseFailed);
+            }
             _ => {
                 let tok = par.next()?;
                 par.unexpected_token_error(&tok, "failed to parse `impl` definition body", vec![]);
@@ -321,7 +329,18 @@ pub fn parse_field(
 
     let typ = parse_type_desc(par)?;
     let value = if par.peek() == Some(TokenKind::Eq) {
-        par.next()?;
+        let eq_tok = par.next()?;
+        if matches!(
+            par.peek(),
+            None | Some(TokenKind::Newline | TokenKind::Semi | TokenKind::BraceClose)
+        ) {
+            par.fancy_error(
+                "failed to parse field definition",
+                vec![Label::primary(eq_tok.span, "expected a value after `=`")],
+                vec![],
+            );
+            return Err(ParseFailed);
+        }
         Some(parse_expr(par)?)
     } else {
         None
@@ -404,6 +423,33 @@ pub fn parse_opt_qualifier(par: &mut Parser, tk: TokenKind) -> Option<Span> {
     }
 }
 
+/// Parse an optional `pub` qualifier, reporting an error if it's repeated.
+pub fn parse_pub_qualifier(par: &mut Parser) -> ParseResult<Option<Span>> {
+    let pub_qual = parse_opt_qualifier(par, TokenKind::Pub);
+    if let Some(pub_span) = pub_qual {
+        check_duplicate_pub(par, pub_span)?;
+    }
+    Ok(pub_qual)
+}
+
+/// Report an error if the next token is a second `pub`, given the span of
+/// the `pub` that was just parsed.
+pub fn check_duplicate_pub(par: &mut Parser, pub_span: Span) -> ParseResult<()> {
+    if par.peek() == Some(TokenKind::Pub) {
+        let tok = par.next()?;
+        par.fancy_error(
+            "duplicate `pub` qualifier",
+            vec![
+                Label::primary(tok.span, "remove this `pub`"),
+                Label::secondary(pub_span, "first `pub` here"),
+            ],
+            vec![],
+        );
+        return Err(ParseFailed);
+    }
+    Ok(())
+}
+
 /// Parse an angle-bracket-wrapped list of generic arguments (eg. the tail end
 /// of `Map<address, u256>`).
 /// # Panics
@@ -548,7 +594,7 @@ pub fn parse_type_desc(par: &mut Parser) -> ParseResult<Node<TypeDesc>> {
                     Node::new(TypeDesc::Path(path), span)
                 }
                 Some(Lt) => {
-                    let args = parse_generic_args(par)?;
+                    let args = par.nested(parse_generic_args)?;
                     let span = name.span + args.span;
                     Node::new(
                         TypeDesc::Generic {
@@ -577,7 +623,7 @@ pub fn parse_type_desc(par: &mut Parser) -> ParseResult<Node<TypeDesc>> {
                     }
 
                     Name | ParenOpen => {
-                        let item = parse_type_desc(par)?;
+                        let item = par.nested(parse_type_desc)?;
                         span += item.span;
                         items.push(item);
                         if par.peek_or_err()? == Comma {
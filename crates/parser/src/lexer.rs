@@ -82,6 +82,32 @@ mod tests {
         assert!(lex.next().is_none());
     }
 
+    #[test]
+    fn strings_quotes_and_newlines() {
+        check("'single' \"double\"", &[Text, Text]);
+        // A string may contain literal newlines; no `Newline` tokens are produced.
+        check("\"one\ntwo\" x", &[Text, Name]);
+    }
+
+    #[test]
+    fn string_trailing_backslash() {
+        // The backslash escapes the closing quote, so the string is unterminated.
+        let mut lex = Lexer::new(SourceFileId::dummy_file(), r#""abc\""#);
+        let tok = lex.next().unwrap();
+        assert_eq!(tok.kind, Error);
+        assert_eq!(tok.span.start, 0);
+    }
+
+    #[test]
+    fn unterminated_string_position() {
+        // The error token starts at the opening quote.
+        let src = "x\n  \"abc";
+        let tok = Lexer::new(SourceFileId::dummy_file(), src)
+            .find(|tok| tok.kind == Error)
+            .unwrap();
+        assert_eq!(tok.span.start, src.find('"').unwrap());
+    }
+
     #[test]
     fn errors() {
         check(
@@ -92,6 +118,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn multi_char_symbols() {
+        check(
+            "a == b != c <= d >= e -> f ** g += h",
+            &[
+                Name, EqEq, Name, NotEq, Name, LtEq, Name, GtEq, Name, Arrow, Name, StarStar, Name,
+                PlusEq, Name,
+            ],
+        );
+        check("a = = b", &[Name, Eq, Eq, Name]);
+    }
+
+    #[test]
+    fn numbers() {
+        check("42 0xdeadBEEF 0o17 0b1010", &[Int, Hex, Octal, Binary]);
+        check("0x 0o 0b", &[Error, Error, Error]);
+        check("0x + 1", &[Error, Plus, Int]);
+    }
+
+    #[test]
+    fn number_underscores() {
+        check(
+            "1_000 0xdead_BEEF 0o7_7 0b1010_1010",
+            &[Int, Hex, Octal, Binary],
+        );
+        // Underscores are only allowed between digits.
+        check("1__0", &[Int, Name]);
+        check("_1", &[Name]);
+        check("0x_ff", &[Error, Name]);
+        check("0xff_", &[Hex, Name]);
+    }
+
+    #[test]
+    fn keyword_prefixes() {
+        check(
+            "true false trueish false_ nothing",
+            &[True, False, Name, Name, Name],
+        );
+        check("self.x selfish", &[SelfValue, Dot, Name, Name]);
+    }
+
+    #[test]
+    fn line_endings() {
+        let expected = &[Name, Newline, Name, Newline, Name, Newline, Name];
+        check("a\nb // hi\n  c\n\td", expected);
+        check("a\r\nb // hi\r\n  c\r\n\td", expected);
+        check("a\rb // hi\r  c\r\td", expected);
+    }
+
     #[test]
     fn tabs_and_comment() {
         check(
@@ -29,23 +29,28 @@ impl<'a> Add<&Token<'a>> for Span {
 pub enum TokenKind {
     // Ignoring comments and spaces/tabs for now.
     // If we implement an auto-formatting tool, we'll probably want to change this.
-    #[regex(r"//[^\n]*", logos::skip)]
+    #[regex(r"//[^\r\n]*", logos::skip)]
     #[regex("[ \t]+", logos::skip)]
     #[error]
     Error,
 
-    #[regex(r"\n[ \t]*")]
+    #[regex(r"(\r\n|\r|\n)[ \t]*")]
     Newline,
 
     #[regex("[a-zA-Z_][a-zA-Z0-9_]*")]
     Name,
     #[regex("[0-9]+(?:_[0-9]+)*")]
     Int,
-    #[regex("0[xX][0-9a-fA-F]+")]
+    // A radix prefix without any digits (eg. `0x`) is lexed as an error,
+    // rather than as the number `0` followed by a name.
+    #[regex("0[xX][0-9a-fA-F]+(?:_[0-9a-fA-F]+)*")]
+    #[regex("0[xX]", |_| false)]
     Hex,
-    #[regex("0[oO][0-7]+")]
+    #[regex("0[oO][0-7]+(?:_[0-7]+)*")]
+    #[regex("0[oO]", |_| false)]
     Octal,
-    #[regex("0[bB][0-1]+")]
+    #[regex("0[bB][0-1]+(?:_[0-1]+)*")]
+    #[regex("0[bB]", |_| false)]
     Binary,
     // Float,
     #[regex(r#""([^"\\]|\\.)*""#)]
@@ -310,7 +315,7 @@ impl TokenKind {
             Arrow => "symbol `->`",
             FatArrow => "symbol `=>`",
 
-            Error => unreachable!(), // TODO this is reachable
+            Error => "an invalid token",
         }
     }
 }
@@ -5,6 +5,7 @@ pub use lexer::{Token, TokenKind};
 mod parser;
 pub use parser::{Label, ParseFailed, ParseResult, Parser};
 pub mod node;
+pub mod visit;
 
 use ast::Module;
 use fe_common::diagnostics::Diagnostic;
@@ -16,10 +17,12 @@ use fe_common::files::SourceFileId;
 /// (which may be empty) to display to the user. If any of the returned
 /// diagnostics are errors, the compilation of this file should ultimately fail.
 ///
-/// If a fatal parse error occurred, the last element of the `Module::body` will
-/// be a `ModuleStmt::ParseError`. The parser currently has very limited ability
-/// to recover from syntax errors; this is just a first meager attempt at returning a
-/// useful AST when there are syntax errors.
+/// If a module-level item couldn't be parsed, it's replaced by a
+/// `ModuleStmt::ParseError` in the `Module::body`, and parsing resumes at the
+/// next unindented line that starts a new item (eg. `fn`, `struct`, `contract`).
+/// The parser currently has very limited ability to recover from syntax errors
+/// within an item; this is just a first meager attempt at returning a useful
+/// AST when there are syntax errors.
 ///
 /// A [`SourceFileId`] is required to associate any diagnostics with the
 /// underlying file.
@@ -35,8 +35,17 @@ pub struct Parser<'a> {
 
     /// The diagnostics (errors and warnings) emitted during parsing.
     pub diagnostics: Vec<Diagnostic>,
+
+    /// The maximum nesting depth of expressions and statements. Parsing fails
+    /// with an error if it's exceeded, rather than overflowing the stack.
+    /// Defaults to 64.
+    pub max_depth: usize,
+
+    depth: usize,
 }
 
+const DEFAULT_MAX_DEPTH: usize = 64;
+
 impl<'a> Parser<'a> {
     /// Create a new parser for a source code string and associated file id.
     pub fn new(file_id: SourceFileId, content: &'a str) -> Self {
@@ -46,6 +55,8 @@ impl<'a> Parser<'a> {
             buffered: vec![],
             enclosure_stack: vec![],
             diagnostics: vec![],
+            max_depth: DEFAULT_MAX_DEPTH,
+            depth: 0,
         }
     }
 
@@ -66,12 +77,17 @@ impl<'a> Parser<'a> {
                 if let Some(open) = self.enclosure_stack.pop() {
                     if !enclosure_tokens_match(open.token_kind, tok.kind) {
                         // TODO: we should search the enclosure_stack
-                        //  for the last matching enclosure open token.
-                        //  If any enclosures are unclosed, we should emit an
-                        //  error, and somehow close their respective ast nodes.
-                        //  We could synthesize the correct close token, or emit
-                        //  a special TokenKind::UnclosedEnclosure or whatever.
-                        todo!()
+                        //  for the last matching enclosure open token,
+                        //  and somehow close the respective ast nodes of any
+                        //  unclosed enclosures.
+                        self.fancy_error(
+                            format!("Mismatched `{}`", tok.text),
+                            vec![
+                                Label::primary(tok.span, "mismatched closing delimiter"),
+                                Label::secondary(open.token_span, "unclosed delimiter"),
+                            ],
+                            vec![],
+                        );
                     }
                 } else {
                     self.error(tok.span, format!("Unmatched `{}`", tok.text));
@@ -79,14 +95,7 @@ impl<'a> Parser<'a> {
             }
             Ok(tok)
         } else {
-            self.error(
-                Span::new(
-                    self.file_id,
-                    self.lexer.source().len(),
-                    self.lexer.source().len(),
-                ),
-                "unexpected end of file",
-            );
+            self.eof_error();
             Err(ParseFailed)
         }
     }
@@ -102,15 +111,22 @@ impl<'a> Parser<'a> {
         if let Some(tk) = self.peek_raw() {
             Ok(tk)
         } else {
-            let index = self.lexer.source().len();
-            self.error(
-                Span::new(self.file_id, index, index),
-                "unexpected end of file",
-            );
+            self.eof_error();
             Err(ParseFailed)
         }
     }
 
+    /// Emit an "unexpected end of file" error. If we're inside an enclosure,
+    /// the innermost unclosed delimiter is pointed out as well.
+    fn eof_error(&mut self) {
+        let index = self.lexer.source().len();
+        let mut labels = vec![Label::primary(Span::new(self.file_id, index, index), "")];
+        if let Some(open) = self.enclosure_stack.last() {
+            labels.push(Label::secondary(open.token_span, "unclosed delimiter"));
+        }
+        self.fancy_error("unexpected end of file", labels, vec![]);
+    }
+
     /// Take a peek at the next token kind. Returns `None` if we've reached the
     /// end of the file.
     pub fn peek(&mut self) -> Option<TokenKind> {
@@ -175,6 +191,48 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Return the span of the next token without consuming it, or `None` if
+    /// we've reached the end of the file.
+    pub fn peek_span(&mut self) -> Option<Span> {
+        self.peek()?;
+        self.buffered.last().map(|tok| tok.span)
+    }
+
+    /// Discard tokens until the next unindented line that starts with a token
+    /// accepted by `is_item_start`, or until the end of the file. Any unclosed
+    /// enclosures are abandoned. This is used to resume parsing at the next
+    /// module-level item after a syntax error.
+    ///
+    /// Returns the span of the discarded tokens (excluding newlines), if any.
+    pub fn skip_to_next_item<F>(&mut self, is_item_start: F) -> Option<Span>
+    where
+        F: Fn(TokenKind) -> bool,
+    {
+        self.enclosure_stack.clear();
+
+        let mut skipped: Option<Span> = None;
+        // The failed parse may have already consumed the newline that ends
+        // the broken line, so check the source directly.
+        let mut at_line_start = self.peek_raw().is_some() && {
+            let start = self.buffered.last().unwrap().span.start;
+            self.lexer.source()[..start].ends_with(['\n', '\r'])
+        };
+        while let Some(tk) = self.peek_raw() {
+            if at_line_start && is_item_start(tk) {
+                break;
+            }
+            let tok = self.next_raw().unwrap();
+            if tok.kind == TokenKind::Newline {
+                // The newline token includes any indentation that follows it.
+                at_line_start = !tok.text.ends_with([' ', '\t']);
+            } else {
+                at_line_start = false;
+                skipped = Some(tok.span + skipped);
+            }
+        }
+        skipped
+    }
+
     /// Assert that the next token kind it matches the expected token
     /// kind, and return it. This should be used in cases where the next token
     /// kind is expected to have been checked already.
@@ -306,6 +364,33 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Call `parse_fn` one nesting level deeper. If this would exceed
+    /// [`Parser::max_depth`], an error is emitted and `parse_fn` isn't called.
+    pub fn nested<T, F>(&mut self, parse_fn: F) -> ParseResult<T>
+    where
+        F: FnOnce(&mut Self) -> ParseResult<T>,
+    {
+        if self.depth >= self.max_depth {
+            let span = self.peek_span().unwrap_or_else(|| {
+                let index = self.lexer.source().len();
+                Span::new(self.file_id, index, index)
+            });
+            self.fancy_error(
+                "maximum nesting depth exceeded",
+                vec![Label::primary(span, "nesting limit reached here")],
+                vec![format!(
+                    "Note: the parser supports at most {} levels of nesting",
+                    self.max_depth
+                )],
+            );
+            return Err(ParseFailed);
+        }
+        self.depth += 1;
+        let result = parse_fn(self);
+        self.depth -= 1;
+        result
+    }
+
     /// Emit an error diagnostic, but don't stop parsing
     pub fn error<S: Into<String>>(&mut self, span: Span, message: S) {
         self.diagnostics.push(Diagnostic {
@@ -347,6 +432,8 @@ impl<'a, 'b> BTParser<'a, 'b> {
             buffered: snapshot.buffered.clone(),
             enclosure_stack: snapshot.enclosure_stack.clone(),
             diagnostics: Vec::new(),
+            max_depth: snapshot.max_depth,
+            depth: snapshot.depth,
         };
         Self { snapshot, parser }
     }
@@ -390,21 +477,21 @@ impl<'a, 'b> std::ops::DerefMut for BTParser<'a, 'b> {
 #[derive(Clone, Debug)]
 struct Enclosure {
     token_kind: TokenKind,
-    _token_span: Span, // TODO: mark mismatched tokens
+    token_span: Span,
     is_block: bool,
 }
 impl Enclosure {
     pub fn block(token_span: Span) -> Self {
         Self {
             token_kind: TokenKind::BraceOpen,
-            _token_span: token_span,
+            token_span,
             is_block: true,
         }
     }
     pub fn non_block(token_kind: TokenKind, token_span: Span) -> Self {
         Self {
             token_kind,
-            _token_span: token_span,
+            token_span,
             is_block: false,
         }
     }
@@ -0,0 +1,309 @@
+//! Read-only traversal of the AST.
+//!
+//! Implement [`Visitor`], overriding the methods for the nodes you're
+//! interested in. Each default method calls the matching `walk_*` function,
+//! which visits the node's children; an overriding method should call it too
+//! if it wants the traversal to continue below that node.
+
+use crate::ast::{
+    ConstantDecl, Contract, ContractStmt, Enum, Expr, Field, FuncStmt, Function, FunctionArg,
+    FunctionSignature, GenericArg, GenericParameter, Impl, Module, ModuleStmt, Struct, Trait,
+    TypeAlias, TypeDesc, VariantKind,
+};
+use crate::node::Node;
+
+pub trait Visitor: Sized {
+    fn visit_module(&mut self, module: &Module) {
+        walk_module(self, module)
+    }
+
+    fn visit_contract(&mut self, contract: &Node<Contract>) {
+        walk_contract(self, contract)
+    }
+
+    fn visit_struct(&mut self, struct_: &Node<Struct>) {
+        walk_struct(self, struct_)
+    }
+
+    fn visit_enum(&mut self, enum_: &Node<Enum>) {
+        walk_enum(self, enum_)
+    }
+
+    fn visit_trait(&mut self, trait_: &Node<Trait>) {
+        walk_trait(self, trait_)
+    }
+
+    fn visit_impl(&mut self, impl_: &Node<Impl>) {
+        walk_impl(self, impl_)
+    }
+
+    fn visit_type_alias(&mut self, alias: &Node<TypeAlias>) {
+        walk_type_alias(self, alias)
+    }
+
+    fn visit_constant(&mut self, constant: &Node<ConstantDecl>) {
+        walk_constant(self, constant)
+    }
+
+    fn visit_field(&mut self, field: &Node<Field>) {
+        walk_field(self, field)
+    }
+
+    fn visit_function(&mut self, function: &Node<Function>) {
+        walk_function(self, function)
+    }
+
+    fn visit_fn_sig(&mut self, sig: &Node<FunctionSignature>) {
+        walk_fn_sig(self, sig)
+    }
+
+    fn visit_stmt(&mut self, stmt: &Node<FuncStmt>) {
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_expr(&mut self, expr: &Node<Expr>) {
+        walk_expr(self, expr)
+    }
+
+    fn visit_type_desc(&mut self, typ: &Node<TypeDesc>) {
+        walk_type_desc(self, typ)
+    }
+}
+
+pub fn walk_module<V: Visitor>(visitor: &mut V, module: &Module) {
+    for stmt in &module.body {
+        match stmt {
+            ModuleStmt::Contract(contract) => visitor.visit_contract(contract),
+            ModuleStmt::Struct(struct_) => visitor.visit_struct(struct_),
+            ModuleStmt::Enum(enum_) => visitor.visit_enum(enum_),
+            ModuleStmt::Trait(trait_) => visitor.visit_trait(trait_),
+            ModuleStmt::Impl(impl_) => visitor.visit_impl(impl_),
+            ModuleStmt::TypeAlias(alias) => visitor.visit_type_alias(alias),
+            ModuleStmt::Constant(constant) => visitor.visit_constant(constant),
+            ModuleStmt::Function(function) => visitor.visit_function(function),
+            ModuleStmt::Pragma(_) | ModuleStmt::Use(_) | ModuleStmt::ParseError(_) => {}
+        }
+    }
+}
+
+pub fn walk_contract<V: Visitor>(visitor: &mut V, contract: &Node<Contract>) {
+    for field in &contract.kind.fields {
+        visitor.visit_field(field);
+    }
+    for stmt in &contract.kind.body {
+        match stmt {
+            ContractStmt::Function(function) => visitor.visit_function(function),
+        }
+    }
+}
+
+pub fn walk_struct<V: Visitor>(visitor: &mut V, struct_: &Node<Struct>) {
+    for field in &struct_.kind.fields {
+        visitor.visit_field(field);
+    }
+    for function in &struct_.kind.functions {
+        visitor.visit_function(function);
+    }
+}
+
+pub fn walk_enum<V: Visitor>(visitor: &mut V, enum_: &Node<Enum>) {
+    for variant in &enum_.kind.variants {
+        if let VariantKind::Tuple(types) = &variant.kind.kind {
+            for typ in types {
+                visitor.visit_type_desc(typ);
+            }
+        }
+    }
+    for function in &enum_.kind.functions {
+        visitor.visit_function(function);
+    }
+}
+
+pub fn walk_trait<V: Visitor>(visitor: &mut V, trait_: &Node<Trait>) {
+    for sig in &trait_.kind.functions {
+        visitor.visit_fn_sig(sig);
+    }
+}
+
+pub fn walk_impl<V: Visitor>(visitor: &mut V, impl_: &Node<Impl>) {
+    visitor.visit_type_desc(&impl_.kind.receiver);
+    for function in &impl_.kind.functions {
+        visitor.visit_function(function);
+    }
+}
+
+pub fn walk_type_alias<V: Visitor>(visitor: &mut V, alias: &Node<TypeAlias>) {
+    visitor.visit_type_desc(&alias.kind.typ);
+}
+
+pub fn walk_constant<V: Visitor>(visitor: &mut V, constant: &Node<ConstantDecl>) {
+    visitor.visit_type_desc(&constant.kind.typ);
+    visitor.visit_expr(&constant.kind.value);
+}
+
+pub fn walk_field<V: Visitor>(visitor: &mut V, field: &Node<Field>) {
+    visitor.visit_type_desc(&field.kind.typ);
+    if let Some(value) = &field.kind.value {
+        visitor.visit_expr(value);
+    }
+}
+
+pub fn walk_function<V: Visitor>(visitor: &mut V, function: &Node<Function>) {
+    visitor.visit_fn_sig(&function.kind.sig);
+    walk_stmts(visitor, &function.kind.body);
+}
+
+pub fn walk_fn_sig<V: Visitor>(visitor: &mut V, sig: &Node<FunctionSignature>) {
+    for param in &sig.kind.generic_params.kind {
+        if let GenericParameter::Bounded { bound, .. } = param {
+            visitor.visit_type_desc(bound);
+        }
+    }
+    for arg in &sig.kind.args {
+        if let FunctionArg::Regular { typ, .. } = &arg.kind {
+            visitor.visit_type_desc(typ);
+        }
+    }
+    if let Some(typ) = &sig.kind.return_type {
+        visitor.visit_type_desc(typ);
+    }
+}
+
+pub fn walk_stmt<V: Visitor>(visitor: &mut V, stmt: &Node<FuncStmt>) {
+    match &stmt.kind {
+        FuncStmt::Return { value: Some(value) } | FuncStmt::Revert { error: Some(value) } => {
+            visitor.visit_expr(value)
+        }
+        FuncStmt::VarDecl { typ, value, .. } => {
+            visitor.visit_type_desc(typ);
+            if let Some(value) = value {
+                visitor.visit_expr(value);
+            }
+        }
+        FuncStmt::ConstantDecl { typ, value, .. } => {
+            visitor.visit_type_desc(typ);
+            visitor.visit_expr(value);
+        }
+        FuncStmt::Assign { target, value } | FuncStmt::AugAssign { target, value, .. } => {
+            visitor.visit_expr(target);
+            visitor.visit_expr(value);
+        }
+        FuncStmt::For { iter, body, .. } => {
+            visitor.visit_expr(iter);
+            walk_stmts(visitor, body);
+        }
+        FuncStmt::While { test, body } => {
+            visitor.visit_expr(test);
+            walk_stmts(visitor, body);
+        }
+        FuncStmt::If {
+            test,
+            body,
+            or_else,
+        } => {
+            visitor.visit_expr(test);
+            walk_stmts(visitor, body);
+            walk_stmts(visitor, or_else);
+        }
+        FuncStmt::Match { expr, arms } => {
+            visitor.visit_expr(expr);
+            for arm in arms {
+                walk_stmts(visitor, &arm.kind.body);
+            }
+        }
+        FuncStmt::Assert { test, msg } => {
+            visitor.visit_expr(test);
+            if let Some(msg) = msg {
+                visitor.visit_expr(msg);
+            }
+        }
+        FuncStmt::Expr { value } => visitor.visit_expr(value),
+        FuncStmt::Unsafe(body) => walk_stmts(visitor, body),
+        FuncStmt::Return { value: None }
+        | FuncStmt::Revert { error: None }
+        | FuncStmt::Break
+        | FuncStmt::Continue => {}
+    }
+}
+
+pub fn walk_expr<V: Visitor>(visitor: &mut V, expr: &Node<Expr>) {
+    match &expr.kind {
+        Expr::Ternary {
+            if_expr,
+            test,
+            else_expr,
+        } => {
+            visitor.visit_expr(if_expr);
+            visitor.visit_expr(test);
+            visitor.visit_expr(else_expr);
+        }
+        Expr::BoolOperation { left, right, .. }
+        | Expr::BinOperation { left, right, .. }
+        | Expr::CompOperation { left, right, .. } => {
+            visitor.visit_expr(left);
+            visitor.visit_expr(right);
+        }
+        Expr::UnaryOperation { operand, .. } => visitor.visit_expr(operand),
+        Expr::Attribute { value, .. } => visitor.visit_expr(value),
+        Expr::Subscript { value, index } => {
+            visitor.visit_expr(value);
+            visitor.visit_expr(index);
+        }
+        Expr::Call {
+            func,
+            generic_args,
+            args,
+        } => {
+            visitor.visit_expr(func);
+            if let Some(generic_args) = generic_args {
+                walk_generic_args(visitor, &generic_args.kind);
+            }
+            for arg in &args.kind {
+                visitor.visit_expr(&arg.kind.value);
+            }
+        }
+        Expr::List { elts } | Expr::Tuple { elts } => {
+            for elt in elts {
+                visitor.visit_expr(elt);
+            }
+        }
+        Expr::Repeat { value, len } => {
+            visitor.visit_expr(value);
+            walk_generic_args(visitor, std::slice::from_ref(&len.kind));
+        }
+        Expr::Bool(_)
+        | Expr::Name(_)
+        | Expr::Path(_)
+        | Expr::Num(_)
+        | Expr::Str(_)
+        | Expr::Unit => {}
+    }
+}
+
+pub fn walk_type_desc<V: Visitor>(visitor: &mut V, typ: &Node<TypeDesc>) {
+    match &typ.kind {
+        TypeDesc::Tuple { items } => {
+            for item in items.iter() {
+                visitor.visit_type_desc(item);
+            }
+        }
+        TypeDesc::Generic { args, .. } => walk_generic_args(visitor, &args.kind),
+        TypeDesc::Unit | TypeDesc::Base { .. } | TypeDesc::Path(_) | TypeDesc::SelfType => {}
+    }
+}
+
+fn walk_stmts<V: Visitor>(visitor: &mut V, stmts: &[Node<FuncStmt>]) {
+    for stmt in stmts {
+        visitor.visit_stmt(stmt);
+    }
+}
+
+fn walk_generic_args<V: Visitor>(visitor: &mut V, args: &[GenericArg]) {
+    for arg in args {
+        match arg {
+            GenericArg::TypeDesc(typ) => visitor.visit_type_desc(typ),
+            GenericArg::ConstExpr(expr) => visitor.visit_expr(expr),
+            GenericArg::Int(_) => {}
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use fe_common::db::TestDb;
 use fe_common::diagnostics::diagnostics_string;
 use fe_common::SourceFileId;
+use fe_parser::ast::ModuleStmt;
 use fe_parser::grammar::{expressions, functions, module};
-use fe_parser::Parser;
+use fe_parser::{parse_file, Parser};
 use insta::assert_snapshot;
 
 pub fn err_string<F, T>(test_name: &str, mut parse_fn: F, src: &str) -> String
@@ -58,6 +59,8 @@ contract C {}
 }
 
 test_parse_err! { contract_bad_name, module::parse_module, "contract 1X {\n x: u8 \n}" }
+test_parse_err! { contract_invalid_token_name, module::parse_module, "contract @ {}" }
+test_parse_err! { contract_missing_brace, module::parse_module, "contract Foo" }
 test_parse_err! { contract_field_after_def, module::parse_module, r#"
 contract C {
   fn f() {}
@@ -68,13 +71,22 @@ contract C {
 test_parse_err! { type_desc_path_number, module::parse_module, "type Foo = some::mod::Foo::5000" }
 test_parse_err! { contract_const_pub, module::parse_module, "contract C {\n const pub x: u8\n}" }
 test_parse_err! { contract_const_fn, module::parse_module, "contract C {\n const fn f() {}\n}" }
+test_parse_err! { contract_pub_pub, module::parse_module, "contract C {\n  pub pub fn f() {}\n}" }
+test_parse_err! { contract_field_missing_value, module::parse_module, "contract C {\n  owner: address =\n}" }
+test_parse_err! { struct_field_missing_value, module::parse_module, "struct S {\n  x: u8 = ;\n}" }
+test_parse_err! { const_missing_colon, module::parse_module, "const FOO u8 = 1" }
 test_parse_err! { expr_bad_prefix, expressions::parse_expr, "*x + 1" }
 test_parse_err! { expr_path_left, expressions::parse_expr, "(1 + 2)::foo::bar" }
 test_parse_err! { expr_path_right, expressions::parse_expr, "foo::10::bar" }
 test_parse_err! { expr_dotted_number, expressions::parse_expr, "3.14" }
 test_parse_err! { expr_call_eq_label, expressions::parse_expr, "foo(bar=1, baz = 2)" }
 test_parse_err! { expr_assignment, expressions::parse_expr, "1 + (x = y)" }
+test_parse_err! { expr_mismatched_delim, expressions::parse_expr, "(1, 2]" }
 test_parse_err! { for_no_in, functions::parse_stmt, "for x {}" }
+test_parse_err! { stmt_assert_no_condition, functions::parse_stmt, "assert" }
+test_parse_err! { stmt_assert_no_condition_semi, functions::parse_stmt, "assert;" }
+test_parse_err! { fn_assert_no_condition, module::parse_module, "fn f() { assert }" }
+test_parse_err! { stmt_revert_comma, functions::parse_stmt, "revert MyError(1), 2" }
 test_parse_err! { fn_no_args, module::parse_module, "fn f {\n  return 5\n}" }
 test_parse_err! { fn_unsafe_pub, module::parse_module, "unsafe pub fn f() {\n  return 5 }" }
 test_parse_err! { fn_def_kw, module::parse_module, "contract C {\n pub def f(x: u8){\n  return x \n}\n}" }
@@ -83,7 +95,24 @@ test_parse_err! { fn_invalid_bound, module::parse_module, "pub fn f<T:(u8, u8)>(
 test_parse_err! { use_bad_name, module::parse_use, "use x as 123" }
 test_parse_err! { module_bad_stmt, module::parse_module, "if x { y }" }
 test_parse_err! { module_nonsense, module::parse_module, "))" }
+test_parse_err! { module_stray_name, module::parse_module, "struct S {\n  x: u8\n}\nxyz\n" }
+test_parse_err! { module_pub_pub, module::parse_module, "pub pub fn f() {}" }
+test_parse_err! { module_recovery, module::parse_module, r#"const A u8 = 1
+struct S {
+  x: u8
+}
+contract C {
+  x u8
+}
+"# }
 test_parse_err! { struct_bad_field_name, module::parse_module, "struct f {\n pub type }" }
+test_parse_err! { struct_keyword_field_name, module::parse_module, "struct S {\n  contract: u256\n}" }
+test_parse_err! { struct_unclosed, module::parse_module, "struct S {\n  x: u8" }
+test_parse_err! { struct_field_after_fn, module::parse_module, "struct S {\n  fn f() {}\n  x: u8\n}" }
+test_parse_err! { struct_field_pub_pub, module::parse_module, "struct S {\n  pub pub x: u8\n}" }
+test_parse_err! { impl_pub_pub, module::parse_module, "impl T for S {\n  pub pub fn f() {}\n}" }
+test_parse_err! { enum_typed_field, module::parse_module, "enum Color {\n  Red\n  x: u8\n}" }
+test_parse_err! { trait_fn_with_body, module::parse_module, "trait T {\n  fn f() -> u8 {\n    return 1\n  }\n}" }
 test_parse_err! { stmt_vardecl_attr, functions::parse_stmt, "f.s : u" }
 test_parse_err! { stmt_vardecl_tuple, functions::parse_stmt, "(a, x+1) : u256" }
 test_parse_err! { stmt_vardecl_tuple_empty, functions::parse_stmt, "(a, ()) : u256" }
@@ -96,6 +125,9 @@ test_parse_err! { stmt_vardecl_invalid_name, functions::parse_stmt, "let x + y:
 test_parse_err! { number_end_with_underscore, functions::parse_stmt, "42_42_"}
 test_parse_err! { array_old_syntax, functions::parse_stmt, "let x: u8[10]" }
 test_parse_err! { array_old_syntax_invalid, functions::parse_stmt, "let x: u8[10" }
+test_parse_err! { type_array_negative_size, functions::parse_stmt, "let x: Array<u8, -1>" }
+test_parse_err! { type_map_missing_comma, functions::parse_stmt, "let x: Map<address u256>" }
+test_parse_err! { type_generic_unclosed, functions::parse_stmt, "let x: Array<u8, 3" }
 test_parse_err! { self_const, module::parse_module, "const self: u8 = 10" }
 test_parse_err! { self_contract, module::parse_module, "contract self {}" }
 test_parse_err! { self_struct, module::parse_module, "struct self {}" }
@@ -118,3 +150,52 @@ fn string_invalid_escape() {
     );
     assert_snapshot!(err);
 }
+
+#[test]
+fn module_recovery_keeps_valid_items() {
+    let src = "const A u8 = 1\nstruct S {\n  x: u8\n}\ncontract C {\n  x u8\n}\n";
+    let (module, diags) = parse_file(SourceFileId::dummy_file(), src);
+
+    assert_eq!(diags.len(), 2);
+    assert!(matches!(
+        module.body.as_slice(),
+        [
+            ModuleStmt::ParseError(_),
+            ModuleStmt::Struct(_),
+            ModuleStmt::ParseError(_)
+        ]
+    ));
+
+    // The broken expression ends at the line break, so the newline has already
+    // been consumed when recovery starts.
+    let src = "const A: u8 =\nstruct S {}\n";
+    let (module, diags) = parse_file(SourceFileId::dummy_file(), src);
+
+    assert_eq!(diags.len(), 1);
+    assert!(matches!(
+        module.body.as_slice(),
+        [ModuleStmt::ParseError(_), ModuleStmt::Struct(_)]
+    ));
+}
+
+test_parse_err! { type_nesting_too_deep, functions::parse_stmt, &format!("let x: {}", "(".repeat(70)) }
+test_parse_err! { pattern_nesting_too_deep, functions::parse_stmt, &format!("match x {{\n  {}", "(".repeat(70)) }
+
+#[test]
+fn expr_nesting_too_deep() {
+    for src in [
+        format!("{}1{}", "(".repeat(100), ")".repeat(100)),
+        "(".repeat(100),
+    ] {
+        let mut parser = Parser::new(SourceFileId::dummy_file(), &src);
+
+        assert!(expressions::parse_expr(&mut parser).is_err());
+        assert_eq!(parser.diagnostics.len(), 1);
+        assert_eq!(
+            parser.diagnostics[0].message,
+            "maximum nesting depth exceeded"
+        );
+        // The label points at the first paren past the limit.
+        assert_eq!(parser.diagnostics[0].labels[0].span.start, 64);
+    }
+}
@@ -1,10 +1,10 @@
 use fe_common::db::TestDb;
 use fe_common::diagnostics::print_diagnostics;
 use fe_common::utils::ron::to_ron_string_pretty;
-use fe_common::SourceFileId;
+use fe_common::{SourceFileId, Span};
 use fe_parser::grammar::{expressions, functions, module, types};
 use fe_parser::node::Node;
-use fe_parser::{ast, ParseResult, Parser};
+use fe_parser::{ast, parse_file, ParseResult, Parser};
 use insta::assert_snapshot;
 use serde::Serialize;
 use wasm_bindgen_test::wasm_bindgen_test;
@@ -44,6 +44,19 @@ where
     }
 }
 
+/// Parse a whole module, panicking if there are any diagnostics.
+pub fn parse_ok(src: &str) -> ast::Module {
+    let mut db = TestDb::default();
+    let id = SourceFileId::new_local(&mut db, "parse_ok", src.into());
+    let (module, diags) = parse_file(id, src);
+
+    if !diags.is_empty() {
+        print_diagnostics(&db, &diags);
+        panic!("parse error");
+    }
+    module
+}
+
 fn try_parse_module(par: &mut Parser) -> ParseResult<Node<ast::Module>> {
     // This is just to make `ast_string` above work; it's fine.
     Ok(module::parse_module(par))
@@ -70,8 +83,13 @@ test_parse! { expr_call1, expressions::parse_expr, "foo()" }
 test_parse! { expr_call2, expressions::parse_expr, "foo(1,2,x:3)" }
 test_parse! { expr_call3, expressions::parse_expr, "bing.foo<Bar>(x:3)" }
 test_parse! { expr_call4, expressions::parse_expr, "bang.bing.foo<Bar, Baz>(26, 42)" }
+test_parse! { expr_call_chained, expressions::parse_expr, "foo(1)(2)" }
+test_parse! { expr_call_multiline, expressions::parse_expr, "foo(\n  a,\n  b,\n)" }
 test_parse! { expr_attr1, expressions::parse_expr, "foo.bar[0][y]" }
 test_parse! { expr_attr2, expressions::parse_expr, "a[x].b[y](1)" }
+test_parse! { expr_attr3, expressions::parse_expr, "a.b.c" }
+test_parse! { expr_subscript_attr, expressions::parse_expr, "self.balances[msg.sender]" }
+test_parse! { expr_subscript_call, expressions::parse_expr, "arr[get(i)]" }
 test_parse! { expr_num1, expressions::parse_expr, "12345" }
 test_parse! { expr_num2, expressions::parse_expr, "00001" }
 test_parse! { expr_num3, expressions::parse_expr, "1000_000" }
@@ -81,25 +99,40 @@ test_parse! { expr_path_call, expressions::parse_expr, "foo::bar::abc1()" }
 test_parse! { expr_string, expressions::parse_expr, r#""hi \tmom\n""# }
 test_parse! { expr_list, expressions::parse_expr, "[]" }
 test_parse! { expr_list2, expressions::parse_expr, "[x, y, z,]" }
+test_parse! { expr_list_calls, expressions::parse_expr, "[f(), g(1), x[0]]" }
 test_parse! { expr_repeat, expressions::parse_expr, "[true; 42]" }
 test_parse! { expr_repeat2, expressions::parse_expr, "[5 + 4; 26]" }
 test_parse! { expr_ternary, expressions::parse_expr, "x + 1 if y + 2 else z + 3" }
+test_parse! { expr_ternary_nested, expressions::parse_expr, "a if p else (b if q else c)" }
+test_parse! { expr_ternary_or, expressions::parse_expr, "a or b if c else d" }
+test_parse! { expr_call_ternary_arg, expressions::parse_expr, "foo(a if b else c)" }
 test_parse! { expr_group, expressions::parse_expr, "(1 + 2) * 3" }
 test_parse! { expr_tuple1, expressions::parse_expr, "(1,)" }
 test_parse! { expr_tuple2, expressions::parse_expr, "(1, 2, \n 3)" }
 test_parse! { expr_tuple3, expressions::parse_expr, "(1, (2 + 3), (3 * 4, 5))" }
 test_parse! { expr_unit, expressions::parse_expr, "()" }
+test_parse! { expr_group_name, expressions::parse_expr, "(x)" }
 
 test_parse! { ops_not, expressions::parse_expr, "x and not y" }
 test_parse! { ops_math, expressions::parse_expr, "a + b * -c ** d / e % f" }
+test_parse! { ops_math_prec, expressions::parse_expr, "1 + 2 * 3" }
+test_parse! { ops_sub_assoc, expressions::parse_expr, "a - b - c" }
 test_parse! { ops_neg, expressions::parse_expr, "-x" }
 test_parse! { ops_bnot, expressions::parse_expr, "~x" }
+test_parse! { ops_neg_add, expressions::parse_expr, "-1 + 2" }
+test_parse! { ops_neg_attr, expressions::parse_expr, "-a.b" }
+test_parse! { ops_not_and, expressions::parse_expr, "not a and b" }
 // bitwise op precedence: shift > and > xor > or
 test_parse! { ops_bit1, expressions::parse_expr, "a & b >> c" }
 test_parse! { ops_bit2, expressions::parse_expr, "a ^ b & c" }
 test_parse! { ops_bit3, expressions::parse_expr, "a | b ^ c" }
 test_parse! { ops_shift, expressions::parse_expr, "a << b >> c" }
+test_parse! { ops_shift_add, expressions::parse_expr, "a << 2 + 1" }
+test_parse! { ops_bit_or_and, expressions::parse_expr, "a | b & c" }
+test_parse! { ops_cmp, expressions::parse_expr, "a < b" }
+test_parse! { ops_cmp_chain, expressions::parse_expr, "a < b <= c" }
 test_parse! { ops_bool, expressions::parse_expr, "a or b and c" }
+test_parse! { ops_bool2, expressions::parse_expr, "a and b or c" }
 
 test_parse! { stmt_assert_no_msg, functions::parse_stmt, "assert x == y" }
 test_parse! { stmt_assert_msg, functions::parse_stmt, "assert x == y, z" }
@@ -114,15 +147,23 @@ test_parse! { stmt_aug_xor, functions::parse_stmt, "x ^= y" }
 test_parse! { stmt_aug_lsh, functions::parse_stmt, "x <<= y" }
 test_parse! { stmt_aug_rsh, functions::parse_stmt, "x >>= y" }
 test_parse! { stmt_aug_exp, functions::parse_stmt, "x **= y" }
+test_parse! { stmt_assign, functions::parse_stmt, "x = 1" }
+test_parse! { stmt_assign_attr, functions::parse_stmt, "self.total = 0" }
+test_parse! { stmt_aug_assign_subscript, functions::parse_stmt, "balances[x] += amount" }
+test_parse! { stmt_expr_call, functions::parse_stmt, "self.update()" }
 test_parse! { stmt_path_type, functions::parse_stmt, "let x: foo::Bar = foo::Bar(1, 2)" }
 test_parse! { stmt_return1, functions::parse_stmt, "return" }
 test_parse! { stmt_return2, functions::parse_stmt, "return x" }
 test_parse! { stmt_return3, functions::parse_stmt, "return not x" }
+test_parse! { stmt_return4, functions::parse_stmt, "return 42" }
+test_parse! { stmt_return_tuple, functions::parse_stmt, "return (x, y)" }
 test_parse! { stmt_revert1, functions::parse_stmt, "revert" }
 test_parse! { stmt_revert2, functions::parse_stmt, "revert something" }
+test_parse! { stmt_revert_call, functions::parse_stmt, "revert MyError(code: 1)" }
 
 test_parse! { stmt_if, functions::parse_stmt, "if a { \n b }" }
 test_parse! { stmt_if2, functions::parse_stmt, "if a { b } else if c { d } else if e { \n f } \n else {\n g }" }
+test_parse! { stmt_if_nested, functions::parse_stmt, "if a {\n  if b {\n    c\n  } else {\n    d\n  }\n}" }
 test_parse! { stmt_match, functions::parse_stmt, r#"match my_enum {
     MyEnum::Unit => {
         return 0
@@ -160,16 +201,21 @@ test_parse! { stmt_match4, functions::parse_stmt, r#"match my_enum {
     }
 }"# }
 test_parse! { stmt_while, functions::parse_stmt, "while a > 5 { \n a -= 1 }" }
+test_parse! { stmt_while_break, functions::parse_stmt, "while true {\n  if done {\n    break\n  }\n  x += 1\n}" }
+test_parse! { stmt_break, functions::parse_stmt, "break" }
 test_parse! { stmt_for, functions::parse_stmt, "for a in b[0] {}" }
+test_parse! { stmt_for_nested, functions::parse_stmt, "for row in grid {\n  for cell in row {\n    total += cell\n  }\n}" }
 test_parse! { stmt_var_decl_name, functions::parse_stmt, "let foo: u256 = 1" }
 test_parse! { stmt_var_decl_mut, functions::parse_stmt, "let mut x: Foo" }
 test_parse! { stmt_var_decl_tuple, functions::parse_stmt, "let (foo, bar): (u256, u256) = (10, 10)" }
 test_parse! { stmt_var_decl_tuples, functions::parse_stmt, "let (a, (b, (c, d))): x" }
 test_parse! { stmt_semicolons, functions::parse_stmt, "if a { b; c; d; for x in y {}; }" }
 test_parse! { type_def, try_parse_module, "type X = Map<address, u256>" }
+test_parse! { type_def_base, try_parse_module, "type Wei = u256" }
 test_parse! { pub_type_def, try_parse_module, "pub type X = Map<address, u256>" }
 test_parse! { type_name, types::parse_type_desc, "MyType" }
 test_parse! { type_array, types::parse_type_desc, "Array<address, 25>" }
+test_parse! { type_array_zero, types::parse_type_desc, "Array<u8, 0>" }
 test_parse! { type_3d, types::parse_type_desc, "Array<Array<Array<u256, 4>, 4>, 4>" }
 test_parse! { type_string, types::parse_type_desc, "string<100>" }
 test_parse! { type_generic, types::parse_type_desc, "foo<a, b<c>, Array<d, 10>>" }
@@ -183,6 +229,11 @@ test_parse! { type_unit, types::parse_type_desc, "()" }
 
 test_parse! { fn_def, try_parse_module, "fn transfer(from sender: address, to recip: address, _ val: u64) -> bool {\n false \n}"}
 
+test_parse! { fn_def_no_params, try_parse_module, "fn foo() {}" }
+test_parse! { fn_def_one_param, try_parse_module, "fn foo(x: u8) {}" }
+test_parse! { fn_def_array_return, try_parse_module, "fn foo() -> Array<u256, 3> {}" }
+test_parse! { fn_def_tuple_return, try_parse_module, "fn foo() -> (u256, address) {}" }
+test_parse! { fn_def_semicolons, try_parse_module, "fn g() {\n  a = 1; b = 2;\n}" }
 test_parse! { fn_def_generic, try_parse_module, "fn foo<T, R: Event>(this: T, that: R, _ val: u64) -> bool { false }"}
 test_parse! { fn_def_pub, try_parse_module, "pub fn foo21(x: bool, y: address,) -> bool { x }"}
 test_parse! { fn_def_unsafe, try_parse_module, "unsafe fn foo21(x: bool, y: address,) -> bool {\n x\n}"}
@@ -190,6 +241,8 @@ test_parse! { fn_def_pub_unsafe, try_parse_module, "pub unsafe fn foo21(x: bool,
 test_parse! { fn_def_mut_args, try_parse_module, "fn transfer(mut from sender: address, to recip: address, mut val: u64) -> bool { \n return false \n}"}
 test_parse! { const_def, try_parse_module, "const FOO: i32 = 1" }
 test_parse! { pub_const_def, try_parse_module, "pub const FOO: i32 = 1" }
+test_parse! { const_def_arith, try_parse_module, "const MAX_SUPPLY: u256 = 100 * 100" }
+test_parse! { const_def_negative, try_parse_module, "const MIN: i128 = -100" }
 test_parse! { pragma1, module::parse_pragma, "pragma 0.1.0" }
 test_parse! { pragma2, module::parse_pragma, "pragma 0.1.0-alpha" }
 test_parse! { pragma3, module::parse_pragma, "pragma >= 1.2, < 1.5" }
@@ -217,6 +270,14 @@ test_parse! { struct_def, try_parse_module, r#"struct S {
   unsafe fn bar() {}
 }"# }
 test_parse! { empty_struct_def, try_parse_module, "struct S {}" }
+test_parse! { struct_def_single_field, try_parse_module, "struct Point {\n  x: u256\n}" }
+test_parse! { struct_def_fields_one_line, try_parse_module, "struct Person {\n  name: u256; age: u8\n  id: u256\n}" }
+test_parse! { struct_def_blank_line, try_parse_module, "struct Greet {\n  name: u256\n\n  age: u8\n}" }
+test_parse! { struct_def_field_named_indexed, try_parse_module, r#"struct Transfer {
+  #indexed
+  sender: address
+  indexed: u256
+}"# }
 
 test_parse! { enum_def, try_parse_module, r#"enum E {
     Unit1
@@ -226,6 +287,11 @@ test_parse! { enum_def, try_parse_module, r#"enum E {
 }"# }
 test_parse! { enum_enum_def, try_parse_module, r#"enum E {}"# }
 
+test_parse! { trait_def, try_parse_module, r#"trait Token {
+  fn name() -> u256;
+  fn transfer(to: address, value: u256) -> bool;
+}"# }
+
 test_parse! { contract_def, try_parse_module, r#"contract Foo {
   x: address
   pub y: u8
@@ -239,6 +305,15 @@ test_parse! { contract_def, try_parse_module, r#"contract Foo {
 
 test_parse! { empty_contract_def, try_parse_module, "contract Foo {}" }
 
+test_parse! { contract_def_blank_lines, try_parse_module, r#"
+
+contract Foo {
+  x: address
+  pub y: u8
+}
+
+"# }
+
 test_parse! { pub_contract_def, try_parse_module, r#"
 pub contract Foo {
     pub fn foo() -> u8 {
@@ -305,3 +380,56 @@ contract Foo {
     }
 }
 "# }
+
+#[test]
+fn module_serde_round_trip() {
+    let module = parse_ok(fe_test_files::fixture("demos/erc20_token.fe"));
+
+    // Node ids aren't serialized, so compare the serialized forms.
+    let serialized = to_ron_string_pretty(&module).unwrap();
+    let deserialized: ast::Module = ron::de::from_str(&serialized).unwrap();
+    assert_eq!(to_ron_string_pretty(&deserialized).unwrap(), serialized);
+}
+
+#[test]
+fn module_item_accessors() {
+    let src = r#"struct Approval {
+  value: u256
+}
+
+contract Token {
+  supply: u256
+}
+
+struct Transfer {
+  value: u256
+}
+
+fn double(x: u256) -> u256 {
+  return x * 2
+}
+"#;
+    let module = parse_ok(src);
+
+    let structs: Vec<_> = module.structs().map(|s| s.name()).collect();
+    assert_eq!(structs, ["Approval", "Transfer"]);
+    let contracts: Vec<_> = module.contracts().map(|c| c.name()).collect();
+    assert_eq!(contracts, ["Token"]);
+    let functions: Vec<_> = module.functions().map(|f| f.name()).collect();
+    assert_eq!(functions, ["double"]);
+}
+
+#[test]
+fn struct_fields_enclosing_span() {
+    let src = "struct Transfer {\n  sender: address\n  #indexed\n  value: u256\n}";
+    let module = parse_ok(src);
+
+    let fields = &module.structs().next().unwrap().kind.fields;
+    let span = Span::enclosing(fields).unwrap();
+    assert_eq!(span, fields[0].span + fields.last().unwrap().span);
+    assert_eq!(
+        &src[span.start..span.end],
+        "sender: address\n  #indexed\n  value: u256"
+    );
+    assert_eq!(Span::enclosing(Vec::<Span>::new()), None);
+}
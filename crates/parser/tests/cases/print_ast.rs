@@ -25,19 +25,24 @@ macro_rules! test_print {
         #[wasm_bindgen_test]
         fn $name() {
             let src = fixture($path);
+            let printed = parse_and_print($path, src);
+
+            // These tests used to assert that the source text and the formatted
+            // text are equal, which is probably more likely to catch errors,
+            // but the diffs from `pretty_assertions` made it hard to debug.
+            // Feel free to change it back or find a better diff printer.
+            // Instead, check that the printed module parses, and prints back to
+            // the same text.
+            assert_eq!(parse_and_print($path, &printed), printed);
+
             if cfg!(target_arch = "wasm32") {
                 fe_common::assert_snapshot_wasm!(
                     concat!("snapshots/cases__print_ast__", stringify!($name), ".snap"),
-                    parse_and_print($path, src)
+                    printed
                 );
             } else {
-                assert_snapshot!(parse_and_print($path, src))
+                assert_snapshot!(printed)
             }
-
-            // These tests used to assert that the source text and the formatted
-            // text are equal, which is probably more likely to catch errors,
-            // but the diffs from `pretty_assertions` made it hard to debug.
-            // Feel free to change it back or find a better diff printer.
         }
     };
 }
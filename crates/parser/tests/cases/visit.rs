@@ -0,0 +1,54 @@
+use crate::parse_ast::parse_ok;
+use fe_parser::ast::{Expr, Field};
+use fe_parser::node::Node;
+use fe_parser::visit::{walk_expr, Visitor};
+
+#[derive(Default)]
+struct Counter {
+    fields: Vec<String>,
+    names: usize,
+}
+
+impl Visitor for Counter {
+    fn visit_field(&mut self, field: &Node<Field>) {
+        self.fields.push(field.name().to_string());
+    }
+
+    fn visit_expr(&mut self, expr: &Node<Expr>) {
+        if let Expr::Name(_) = expr.kind {
+            self.names += 1;
+        }
+        walk_expr(self, expr)
+    }
+}
+
+#[test]
+fn visit_fields_and_names() {
+    let src = r#"struct Transfer {
+  #indexed
+  sender: address
+  value: u256
+}
+
+struct Approval {
+  value: u256
+}
+
+contract Token {
+  supply: u256
+
+  pub fn double(x: u256) -> u256 {
+    if x > 0 {
+      return x * 2
+    }
+    return 0
+  }
+}
+"#;
+    let module = parse_ok(src);
+
+    let mut counter = Counter::default();
+    counter.visit_module(&module);
+    assert_eq!(counter.fields, ["sender", "value", "value", "supply"]);
+    assert_eq!(counter.names, 2);
+}